@@ -0,0 +1,49 @@
+//! Peano and Church numerals against `usize`.
+//!
+//! ```text
+//! cargo bench --bench numerals
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rust_learning_group::{church, peano::Peano};
+
+const SIZES: [usize; 3] = [8, 32, 128];
+
+fn add(c: &mut Criterion) {
+    let mut group = c.benchmark_group("add");
+    for n in SIZES {
+        group.bench_with_input(BenchmarkId::new("usize", n), &n, |b, &n| {
+            b.iter(|| black_box(n) + black_box(n))
+        });
+        let p = Peano::from(n);
+        group.bench_with_input(BenchmarkId::new("peano", n), &p, |b, p| {
+            b.iter(|| usize::from(p.clone() + p.clone()))
+        });
+        let c = church::from_usize::<()>(n);
+        group.bench_with_input(BenchmarkId::new("church", n), &c, |b, c| {
+            b.iter(|| church::to_usize(church::add(c.clone(), c.clone())))
+        });
+    }
+    group.finish();
+}
+
+fn mul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mul");
+    for n in SIZES {
+        group.bench_with_input(BenchmarkId::new("usize", n), &n, |b, &n| {
+            b.iter(|| black_box(n) * black_box(n))
+        });
+        let p = Peano::from(n);
+        group.bench_with_input(BenchmarkId::new("peano", n), &p, |b, p| {
+            b.iter(|| usize::from(p.clone() * p.clone()))
+        });
+        let c = church::from_usize::<()>(n);
+        group.bench_with_input(BenchmarkId::new("church", n), &c, |b, c| {
+            b.iter(|| church::to_usize(church::mult(c.clone(), c.clone())))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, add, mul);
+criterion_main!(benches);
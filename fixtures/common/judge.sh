@@ -108,6 +108,10 @@ else
     score_per_test[$N]=$((100 - $total_score))
 fi
 main_job=$(generate_job "Main" 100 100 "" "")
+# `[limits] time` of exercise.toml, as in the local grader, for the cases
+# without a `test_$i.time` of their own.
+default_time_limit=$(exercise_time_limit exercise.toml)
+default_time_limit=${default_time_limit:-10}
 job_start_time=$(date +%s)
 total_score=0
 final_status=Accepted
@@ -121,20 +125,38 @@ for i in $(seq 1 "$N"); do
     ANS=test_$i.ans
     OUT=test_$i.out
     ERR=test_$i.err
+    TIME=test_$i.time
+    time_limit=$default_time_limit
+    if [ -f "$TIME" ]; then
+        time_limit=$(cat "$TIME")
+    fi
+    # Auxiliary files of the case, staged in a fresh working directory.
+    AUX=test_$i.aux
+    WORK=test_$i.work
+    work_args=()
+    if [ -d "$AUX" ]; then
+        rm -rf "$WORK"
+        cp -r "$AUX" "$WORK"
+        work_args=(-v "./$WORK":/work -w /work)
+    fi
     touch "$OUT"
     touch "$ERR"
     # "./$binary" < "$IN" > "$OUT"
-    case_metrics=$(run_container -t 10 -- \
+    case_metrics=$(run_container -t "$time_limit" -- \
       -v "./$IN":/input:ro \
       -v "./$OUT":/stdout \
       -v "./$ERR":/stderr \
       -v "$binary":/exe \
+      "${work_args[@]}" \
       docker.io/debian:bookworm sh -c "/exe </input >/stdout 2>/stderr")
     case_exit_code=$?
     case_total_score=${score_per_test[$i]}
     case_score=0
     case_status="Accepted"
-    if [ $case_exit_code -ne 0 ]; then
+    if [ $case_exit_code -eq 124 ]; then
+        case_status="Time Limit Exceeded"
+        final_status=$case_status
+    elif [ $case_exit_code -ne 0 ]; then
         case_status="Runtime Error"
         final_status=$case_status
     else
@@ -73,3 +73,18 @@ append_job_to_details() {
   local job=$2
   jq --argjson job "$job" '.jobs += [$job]' <<<"$details"
 }
+
+# The `time` of the `[limits]` table of an exercise.toml, in seconds, or
+# nothing if it has none, like `src/helpers/exercise.rs` reads it.
+exercise_time_limit() {
+  local file=$1
+  [ -f "$file" ] || return 0
+  awk '
+    /^[[:space:]]*\[/ { in_limits = ($0 ~ /^[[:space:]]*\[limits\][[:space:]]*(#.*)?$/); next }
+    in_limits && /^[[:space:]]*time[[:space:]]*=/ {
+      sub(/^[^=]*=[[:space:]]*/, "")
+      sub(/[[:space:]]*(#.*)?$/, "")
+      print
+      exit
+    }' "$file"
+}